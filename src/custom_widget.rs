@@ -0,0 +1,119 @@
+//! A custom widget built directly on conrod's `Widget` trait.
+
+use conrod::{widget, Color, Colorable, Positionable, Sizeable, Widget, WidgetCommon, WidgetStyle};
+
+/// A simple circular button with an optional label.
+#[derive(WidgetCommon)]
+pub struct CircularButton<'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    maybe_label: Option<&'a str>,
+    style: Style,
+}
+
+/// Unique styling for the `CircularButton`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle)]
+pub struct Style {
+    /// Color of the circle.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// Color of the label.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// Font size of the label.
+    #[conrod(default = "theme.font_size_medium")]
+    pub label_font_size: Option<conrod::FontSize>,
+}
+
+/// The ids of the widgets `CircularButton` is made up of, same pattern as
+/// the hand-rolled `Ids` in `main.rs`.
+struct Ids {
+    circle: widget::Id,
+    label: widget::Id,
+}
+
+impl Ids {
+    fn new(mut gen: widget::id::Generator) -> Self {
+        Ids {
+            circle: gen.next(),
+            label: gen.next(),
+        }
+    }
+}
+
+/// The state `CircularButton` persists between updates.
+pub struct State {
+    ids: Ids,
+}
+
+impl<'a> CircularButton<'a> {
+    /// Construct a new `CircularButton` with default styling.
+    pub fn new() -> Self {
+        CircularButton {
+            common: widget::CommonBuilder::default(),
+            maybe_label: None,
+            style: Style::default(),
+        }
+    }
+
+    /// Set the label displayed in the middle of the button.
+    pub fn label(mut self, text: &'a str) -> Self {
+        self.maybe_label = Some(text);
+        self
+    }
+}
+
+impl<'a> Widget for CircularButton<'a> {
+    type State = State;
+    type Style = Style;
+    /// Whether the pointer is currently over the circle.
+    type Event = bool;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State { ids: Ids::new(id_gen) }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs { id, state, rect, ui, style, .. } = args;
+
+        let radius = rect.w().min(rect.h()) / 2.0;
+        let is_over = ui.widget_input(id)
+            .mouse()
+            .map(|mouse| {
+                let [x, y] = mouse.rel_xy();
+                (x * x + y * y).sqrt() <= radius
+            })
+            .unwrap_or(false);
+
+        let color = style.color(&ui.theme);
+        let color = if is_over { color.highlighted() } else { color };
+
+        widget::Oval::fill([radius * 2.0, radius * 2.0])
+            .middle_of(id)
+            .graphics_for(id)
+            .color(color)
+            .set(state.ids.circle, ui);
+
+        if let Some(label) = self.maybe_label {
+            widget::Text::new(label)
+                .middle_of(id)
+                .graphics_for(id)
+                .color(style.label_color(&ui.theme))
+                .font_size(style.label_font_size(&ui.theme))
+                .set(state.ids.label, ui);
+        }
+
+        is_over
+    }
+}
+
+impl<'a> Colorable for CircularButton<'a> {
+    fn color(mut self, color: Color) -> Self {
+        self.style.color = Some(color);
+        self
+    }
+}
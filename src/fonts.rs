@@ -0,0 +1,40 @@
+//! Loads font faces from `assets/fonts` at runtime, found via `find_folder`.
+
+use find_folder;
+
+use conrod::text::font;
+
+/// The faces we load into `ui.fonts`, and the `font::Id`s conrod handed
+/// back for each.
+pub struct Fonts {
+    pub regular: font::Id,
+    pub bold: font::Id,
+    pub italic: font::Id,
+}
+
+impl Fonts {
+    /// `regular` is inserted first, so it gets `font::Id::new(0)` - the id
+    /// `theme()` in `main.rs` assumes before any faces are loaded.
+    pub fn load(ui: &mut conrod::Ui) -> Self {
+        let assets = find_folder::Search::KidsThenParents(3, 5)
+            .for_folder("assets")
+            .expect("expected to find the `assets` folder");
+        let fonts_dir = assets.join("fonts");
+
+        let regular = ui.fonts
+            .insert_from_file(fonts_dir.join("OpenSans-Regular.ttf"))
+            .expect("expected loading OpenSans-Regular.ttf to succeed");
+        let bold = ui.fonts
+            .insert_from_file(fonts_dir.join("OpenSans-Bold.ttf"))
+            .expect("expected loading OpenSans-Bold.ttf to succeed");
+        let italic = ui.fonts
+            .insert_from_file(fonts_dir.join("OpenSans-Italic.ttf"))
+            .expect("expected loading OpenSans-Italic.ttf to succeed");
+
+        Fonts {
+            regular: regular,
+            bold: bold,
+            italic: italic,
+        }
+    }
+}
@@ -1,12 +1,20 @@
 extern crate conrod;
+extern crate find_folder;
 extern crate glium;
 extern crate glutin;
-extern crate rusttype;
+extern crate image;
 
-use conrod::{color, Borderable, Colorable, Positionable};
+use conrod::{color, Borderable, Colorable, Point, Positionable, Rect, Sizeable};
 use conrod::widget::*;
+use conrod::widget::triangles::Triangle;
 
+mod custom_widget;
+mod fonts;
 mod glutin_glue;
+mod threaded_ui;
+
+use custom_widget::CircularButton;
+use fonts::Fonts;
 
 // --
 // In a real app, App would likely be split out into its own module, as would the layout code.
@@ -14,61 +22,117 @@ mod glutin_glue;
 // Things are kept together in main.rs here for simplicity.
 // --
 
-/// Holds everything we need for application state
+/// Opt-in: run with `set_widgets` moved onto a dedicated worker thread,
+/// via the `threaded_ui` module, instead of running inline on the main
+/// thread below. See that module for why you'd want this.
+const THREADED_UI: bool = false;
+
+/// Holds the part of our application state that isn't tied to the GL
+/// context, and so can move freely between threads. See `Gpu` for the
+/// part that can't.
 struct App {
     /// The conrod UI state
     ui: conrod::Ui,
-    /// The handle to the actual window display
-    display: glium::Display,
-    /// A map of all images conrod can render
-    /// Unused unless you render images
-    image_map: conrod::image::Map<glium::texture::Texture2d>,
     /// The state of what IDs we know of
     ids: Ids,
-    /// The conrod renderer
-    renderer: conrod::backend::glium::Renderer,
+    /// The id of our example image in the `Gpu`'s `image_map`, so
+    /// `create_ui` can reference it without needing the (non-`Send`)
+    /// texture itself.
+    image_id: conrod::image::Id,
+    /// The faces loaded by the `fonts` module, so `create_ui` can pick a
+    /// specific one instead of always drawing in the theme's default.
+    fonts: Fonts,
+    /// How many times the counter button has been clicked.
     // In my applications, I also have a few other things here,
     // representing the app's state itself.
+    count: u32,
 }
 
 impl App {
-    pub fn new(window: glium::Display) -> Self {
-        let (width, height) = window
-            .gl_window()
-            .window()
-            .get_inner_size()
-            .expect("expected getting window size to succeed.");
-
-        // Create UI.
-        let mut ui = conrod::UiBuilder::new([width as f64, height as f64]).build();
-        let renderer = conrod::backend::glium::Renderer::new(&window)
-            .expect("expected loading conrod glium renderer to succeed.");
-        let image_map = conrod::image::Map::new();
+    pub fn new(width: f64, height: f64, image_id: conrod::image::Id) -> Self {
+        let mut ui = conrod::UiBuilder::new([width, height]).theme(theme()).build();
+        let fonts = Fonts::load(&mut ui);
 
         let ids = Ids::new(ui.widget_id_generator());
 
         App {
             ui: ui,
-            display: window,
-            image_map: image_map,
             ids: ids,
-            renderer: renderer,
+            image_id: image_id,
+            fonts: fonts,
+            count: 0,
         }
     }
 }
 
-fn load_font() -> rusttype::Font<'static> {
-    let font_data = include_bytes!("../OpenSans-Regular.ttf");
-    let collection = rusttype::FontCollection::from_bytes(font_data as &[u8]);
+/// The theme `App::new` builds the `Ui` with. `font_id` assumes the
+/// `regular` face is the first one `Fonts::load` inserts, since the
+/// theme has to be built before any fonts exist to pick a "real" id from.
+fn theme() -> conrod::Theme {
+    conrod::Theme {
+        font_id: Some(conrod::text::font::Id::new(0)),
+        shape_color: color::CHARCOAL,
+        label_color: color::WHITE,
+        font_size_large: 26,
+        font_size_medium: 18,
+        font_size_small: 12,
+        ..conrod::Theme::default()
+    }
+}
 
-    collection
-        .into_font()
-        .expect("expected loading embedded OpenSans-Regular.ttf font to succeed")
+/// Holds everything tied to the GL context: the window display, the
+/// conrod renderer, and the image map. None of these are `Send`, so they
+/// have to stay on whichever thread created the `glium::Display` -
+/// unlike `App`, which is free to move to a worker thread (see
+/// `threaded_ui`).
+struct Gpu {
+    /// The handle to the actual window display
+    display: glium::Display,
+    /// A map of all images conrod can render
+    /// Unused unless you render images
+    image_map: conrod::image::Map<glium::texture::Texture2d>,
+    /// The conrod renderer
+    renderer: conrod::backend::glium::Renderer,
 }
 
-fn init_window() -> (glutin::EventsLoop, App) {
-    // Create window.
-    let events_loop = glutin::EventsLoop::new();
+impl Gpu {
+    /// Builds the `Gpu` state and uploads our example image, returning the
+    /// `image::Id` it was given so it can be handed to `App` for use in
+    /// `create_ui`.
+    pub fn new(window: glium::Display) -> (Self, conrod::image::Id) {
+        let renderer = conrod::backend::glium::Renderer::new(&window)
+            .expect("expected loading conrod glium renderer to succeed.");
+        let mut image_map = conrod::image::Map::new();
+
+        let texture = load_image_texture(&window);
+        let image_id = image_map.insert(texture);
+
+        (
+            Gpu {
+                display: window,
+                image_map: image_map,
+                renderer: renderer,
+            },
+            image_id,
+        )
+    }
+}
+
+/// Loads our embedded example image and uploads it into a GPU texture
+/// conrod's renderer can draw.
+fn load_image_texture(display: &glium::Display) -> glium::texture::Texture2d {
+    let image_data = include_bytes!("../rust-logo.png");
+    let image = image::load_from_memory(image_data as &[u8])
+        .expect("expected decoding embedded rust-logo.png to succeed")
+        .to_rgba();
+    let (width, height) = image.dimensions();
+    let raw_image = glium::texture::RawImage2d::from_raw_rgba_reversed(&image.into_raw(), (width, height));
+
+    glium::texture::Texture2d::new(display, raw_image)
+        .expect("expected uploading example image texture to succeed")
+}
+
+fn build_window(events_loop: &glutin::EventsLoop) -> glium::Display {
     let window = glutin::WindowBuilder::new()
         .with_dimensions(640, 480)
         .with_title("the-conrod-application");
@@ -77,19 +141,38 @@ fn init_window() -> (glutin::EventsLoop, App) {
         .with_vsync(true)
         .with_multisampling(4);
 
-    let display = glium::Display::new(window, context, &events_loop)
-        .expect("expected initial window creation to succeed");
+    glium::Display::new(window, context, events_loop).expect("expected initial window creation to succeed")
+}
+
+fn init_window() -> (glutin::EventsLoop, App, Gpu) {
+    let events_loop = glutin::EventsLoop::new();
+    let display = build_window(&events_loop);
 
-    let mut app = App::new(display);
+    let (width, height) = display
+        .gl_window()
+        .window()
+        .get_inner_size()
+        .expect("expected getting window size to succeed.");
 
-    // Add font.
-    app.ui.fonts.insert(load_font());
+    let (gpu, image_id) = Gpu::new(display);
+    let app = App::new(width as f64, height as f64, image_id);
 
-    (events_loop, app)
+    (events_loop, app, gpu)
 }
 
 fn main() {
-    let (glutin_loop, mut app) = init_window();
+    if THREADED_UI {
+        threaded_ui::run();
+    } else {
+        run();
+    }
+}
+
+/// Runs the example the plain way: layout and rendering both happen
+/// inline on the main thread. See `threaded_ui::run` for the opt-in
+/// alternative that moves layout onto a worker thread.
+fn run() {
+    let (glutin_loop, mut app, mut gpu) = init_window();
 
     let mut events = glutin_glue::EventLoop::new(glutin_loop);
 
@@ -98,7 +181,7 @@ fn main() {
             glutin_glue::Event::Glutin(event) => {
                 // Pass event onto conrod
                 if let Some(conrod_event) =
-                    conrod::backend::winit::convert_event(event.clone(), &app.display)
+                    conrod::backend::winit::convert_event(event.clone(), &gpu.display)
                 {
                     app.ui.handle_event(conrod_event);
                     control.needs_update(); // let our event loop know we need to update.
@@ -140,12 +223,12 @@ fn main() {
                 create_ui(&mut app);
 
                 if let Some(primitives) = app.ui.draw_if_changed() {
-                    let mut target = app.display.draw();
+                    let mut target = gpu.display.draw();
 
-                    app.renderer.fill(&app.display, primitives, &app.image_map);
+                    gpu.renderer.fill(&gpu.display, primitives, &gpu.image_map);
 
-                    app.renderer
-                        .draw(&app.display, &mut target, &app.image_map)
+                    gpu.renderer
+                        .draw(&gpu.display, &mut target, &gpu.image_map)
                         .expect("expected drawing GUI to display to succeed");
 
                     target
@@ -167,10 +250,58 @@ fn create_ui(app: &mut App) {
         .border(0.0)
         .set(ids.root, ui);
 
-    Text::new("hello")
+    // `Button::set` returns an iterator yielding one item per click that
+    // happened since the last `set_widgets` - this is the whole "immediate
+    // mode" pattern: the widget holds no state of its own, and each frame
+    // we rebuild it fresh from `app.count` below.
+    for _click in Button::new()
+        .color(color::LIGHT_BLUE)
+        .label("Click me!")
+        .label_font_id(app.fonts.bold)
+        .mid_top_of(ids.root)
+        .w_h(120.0, 40.0)
+        .set(ids.button, ui)
+    {
+        app.count += 1;
+    }
+
+    Text::new(&format!("Clicked {} times", app.count))
         .color(color::BLACK)
-        .middle_of(ids.root)
-        .set(ids.label, ui);
+        .font_id(app.fonts.italic)
+        .down_from(ids.button, 20.0)
+        .set(ids.num_label, ui);
+
+    Image::new(app.image_id)
+        .w_h(64.0, 64.0)
+        .down_from(ids.num_label, 20.0)
+        .set(ids.image, ui);
+
+    CircularButton::new()
+        .color(color::ORANGE)
+        .label("circle")
+        .w_h(80.0, 80.0)
+        .down_from(ids.image, 20.0)
+        .set(ids.circular_button, ui);
+
+    // `Triangles` bypasses the usual glyph/rectangle widgets and submits
+    // raw, per-vertex-colored geometry straight to the renderer - useful
+    // for arbitrary shapes and gradients the built-in widgets can't draw.
+    let [circle_x, circle_y] = ui.xy_of(ids.circular_button).unwrap_or([0.0, 0.0]);
+    let top = circle_y - 60.0;
+    let bottom = top - 80.0;
+
+    let left: (Point, color::Rgba) = ([circle_x - 80.0, bottom], color::RED.to_rgb());
+    let right: (Point, color::Rgba) = ([circle_x + 80.0, bottom], color::BLUE.to_rgb());
+    let peak: (Point, color::Rgba) = ([circle_x, top], color::GREEN.to_rgb());
+    let far_right: (Point, color::Rgba) = ([circle_x + 160.0, top], color::YELLOW.to_rgb());
+
+    let tris = vec![Triangle([left, peak, right]), Triangle([peak, right, far_right])];
+
+    let bounding_rect = Rect::from_corners([circle_x - 80.0, bottom], [circle_x + 160.0, top]);
+
+    Triangles::multi_color(tris)
+        .with_bounding_rect(bounding_rect)
+        .set(ids.triangles, ui);
 }
 
 // // This creates a structure and a constructor which takes in a ID generator.
@@ -180,20 +311,29 @@ fn create_ui(app: &mut App) {
 // widget_ids! {
 //     struct Ids {
 //         root,
-//         label,
+//         button,
+//         num_label,
 //     }
 // }
 // would create this:
 struct Ids {
     root: conrod::widget::Id,
-    label: conrod::widget::Id,
+    button: conrod::widget::Id,
+    num_label: conrod::widget::Id,
+    image: conrod::widget::Id,
+    circular_button: conrod::widget::Id,
+    triangles: conrod::widget::Id,
 }
 
 impl Ids {
     pub fn new(mut gen: conrod::widget::id::Generator) -> Self {
         Ids {
             root: gen.next(),
-            label: gen.next(),
+            button: gen.next(),
+            num_label: gen.next(),
+            image: gen.next(),
+            circular_button: gen.next(),
+            triangles: gen.next(),
         }
     }
 }
@@ -0,0 +1,126 @@
+//! Opt-in mode (see `THREADED_UI` in `main.rs`) that runs `create_ui` on a
+//! dedicated worker thread instead of the main thread.
+
+use std::sync::mpsc;
+use std::thread;
+
+use conrod;
+use glutin;
+
+use {build_window, create_ui, glutin_glue, App, Gpu};
+
+/// Sent from the main thread to the UI worker thread.
+enum ToWorker {
+    /// A converted glutin event to feed into conrod.
+    Input(conrod::event::Input),
+    /// The window was refreshed or resized: force a redraw even though no
+    /// widget state changed, matching `run`'s handling of the same events
+    /// in main.rs.
+    ForceRedraw,
+    /// The window is closing; let the worker thread finish.
+    Exit,
+}
+
+/// Runs the example with `create_ui` moved onto its own thread. Behaves
+/// the same as `run` in `main.rs` from the outside - same window, same
+/// widgets - just split across two threads under the hood.
+pub fn run() {
+    let glutin_loop = glutin::EventsLoop::new();
+    let display = build_window(&glutin_loop);
+
+    let (width, height) = display
+        .gl_window()
+        .window()
+        .get_inner_size()
+        .expect("expected getting window size to succeed.");
+
+    let (mut gpu, image_id) = Gpu::new(display);
+    let proxy = glutin_loop.create_proxy();
+
+    let (input_tx, input_rx) = mpsc::channel::<ToWorker>();
+    let (primitives_tx, primitives_rx) = mpsc::channel::<conrod::render::OwnedPrimitives>();
+
+    let worker = thread::spawn(move || {
+        let mut app = App::new(width as f64, height as f64, image_id);
+
+        for message in input_rx {
+            match message {
+                ToWorker::Input(input) => app.ui.handle_event(input),
+                ToWorker::ForceRedraw => app.ui.needs_redraw(),
+                ToWorker::Exit => break,
+            }
+
+            create_ui(&mut app);
+
+            if let Some(primitives) = app.ui.draw_if_changed() {
+                if primitives_tx.send(primitives.owned()).is_err() {
+                    break;
+                }
+                // Wake the main thread's EventsLoop up so it notices the
+                // primitives waiting in `primitives_rx` and redraws.
+                proxy
+                    .wakeup()
+                    .expect("expected waking up the main event loop to succeed");
+            }
+        }
+    });
+
+    let mut events = glutin_glue::EventLoop::new(glutin_loop);
+
+    events.run_loop(|control, glue_event| match glue_event {
+        glutin_glue::Event::Glutin(event) => {
+            if let Some(conrod_event) =
+                conrod::backend::winit::convert_event(event.clone(), &gpu.display)
+            {
+                // The worker thread owns the only `conrod::Ui`, so hand the
+                // event off instead of calling `handle_event` here.
+                let _ = input_tx.send(ToWorker::Input(conrod_event));
+            }
+
+            match event {
+                glutin::Event::WindowEvent { event, .. } => match event {
+                    // When the escape key is pressed or the window is closed, leave the event loop.
+                    glutin::WindowEvent::KeyboardInput {
+                        input:
+                            glutin::KeyboardInput {
+                                virtual_keycode: Some(glutin::VirtualKeyCode::Escape),
+                                ..
+                            },
+                        ..
+                    }
+                    | glutin::WindowEvent::Closed => control.exit(),
+                    // When the window is resized or re-focused, redraw the contents
+                    glutin::WindowEvent::Refresh | glutin::WindowEvent::Resized(..) => {
+                        let _ = input_tx.send(ToWorker::ForceRedraw);
+                    }
+                    _ => (),
+                },
+                // The worker thread's wakeup lands here: drain whatever
+                // primitives are waiting and draw the latest one.
+                glutin::Event::Awakened => {
+                    while let Ok(primitives) = primitives_rx.try_recv() {
+                        let mut target = gpu.display.draw();
+
+                        gpu.renderer
+                            .fill(&gpu.display, primitives.walk(), &gpu.image_map);
+
+                        gpu.renderer
+                            .draw(&gpu.display, &mut target, &gpu.image_map)
+                            .expect("expected drawing GUI to display to succeed");
+
+                        target
+                            .finish()
+                            .expect("expected frame to remain unfinished before calling finish.");
+                    }
+                }
+                _ => (),
+            }
+        }
+        // Nothing to do here: in this mode `create_ui` runs on the worker
+        // thread, not in response to this event.
+        glutin_glue::Event::UpdateUi => (),
+    });
+
+    let _ = input_tx.send(ToWorker::Exit);
+    let _ = worker.join();
+}